@@ -18,7 +18,7 @@
 //!     rng.next_u32();
 //! }
 //!
-//! assert_eq!(2581263997, rng.next_u32());
+//! assert_eq!(2304418894, rng.next_u32());
 //! ```
 //!
 //! # Features
@@ -27,7 +27,11 @@
 //! Allows (de)serialization of the state array using
 //! [serde](https://serde.rs/).
 
-use rand_core::impls::fill_bytes_via_next;
+pub mod reseeding;
+pub mod xorwow64;
+pub mod xorwow128;
+
+use rand_core::block::{BlockRng64, BlockRngCore};
 use rand_core::le::read_u32_into;
 use rand_core::{Error, RngCore, SeedableRng};
 use std::ops::BitXor;
@@ -35,14 +39,73 @@ use std::ops::BitXor;
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
 
+/// Number of `u64` words clocked out per call to [`BlockRngCore::generate`].
+///
+/// `return_u64()` is a bespoke, single-clock combine of two state words, not
+/// two separately-clocked `return_u32()` words, so the buffer is built from
+/// `u64` items (as `xorwow128`/`xorwow64` already do) rather than `u32` ones
+/// — otherwise `next_u64`/`fill_bytes` would splice together unrelated
+/// buffered `u32`s instead of reproducing `return_u64()`.
+const BLOCK_WORDS: usize = 8;
+
+/// Exponent used by `jump()` on every generator in this crate: the
+/// underlying Xorshift state is advanced as if `clock()` had been called
+/// `2^32` times. Chosen well below any generator's state size so that the
+/// Frobenius identity `x^(2^d) = x` (which holds once the exponent reaches
+/// the size `d` of the state, and would turn the jump into a no-op) never
+/// applies.
+pub(crate) const JUMP_EXPONENT: u64 = 1 << 32;
+
+/// Exponent used by `long_jump()`, chosen for the same reason as
+/// [`JUMP_EXPONENT`] and large enough to reach a distant, non-overlapping
+/// substream.
+pub(crate) const LONG_JUMP_EXPONENT: u64 = 1 << 48;
+
+/// Coefficients (low word first) of `x^JUMP_EXPONENT mod M(x)`, where
+/// `M(x)` is the characteristic polynomial over GF(2) of the Xorshift
+/// update with shift triple `(10, 5, 26)` and 3 state words.
+const JUMP_96: [u32; 3] = [0xc57da55a, 0x2fa1aaae, 0x18a1fb09];
+/// Coefficients of `x^LONG_JUMP_EXPONENT mod M(x)`, same map as [`JUMP_96`].
+const LONG_JUMP_96: [u32; 3] = [0x8fead730, 0x6d4fd5d9, 0xf4835fe2];
+
+/// Coefficients of `x^JUMP_EXPONENT mod M(x)` for the shift triple
+/// `(5, 14, 1)` with 4 state words.
+const JUMP_128: [u32; 4] = [0x28849d42, 0x7b040fe5, 0xbd43757b, 0x13080c06];
+/// Coefficients of `x^LONG_JUMP_EXPONENT mod M(x)`, same map as [`JUMP_128`].
+const LONG_JUMP_128: [u32; 4] = [0x4ba3e62a, 0x531e20fc, 0xa90959b9, 0x81071739];
+
+/// Coefficients of `x^JUMP_EXPONENT mod M(x)` for the shift triple
+/// `(2, 1, 4)` with 5 state words.
+const JUMP_160: [u32; 5] = [0x943999e4, 0xc05db913, 0x4e4010f3, 0x9b865d3d, 0xfd64174];
+/// Coefficients of `x^LONG_JUMP_EXPONENT mod M(x)`, same map as [`JUMP_160`].
+const LONG_JUMP_160: [u32; 5] = [0x670f870e, 0xa7bb9766, 0xef013c78, 0xeb4a1373, 0x256f3323];
+
+macro_rules! make_xorwow_core {
+    ($core: ident, $nr: expr) => {
+        #[derive(Debug, Clone, Eq, PartialEq)]
+        #[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+        pub struct $core {
+            s: [u32; $nr],
+            // Weyl sequence increment; must stay odd, see `with_increment`.
+            increment: u32,
+        }
+    };
+}
+
+make_xorwow_core!(Xorwow96Core, 4);
+make_xorwow_core!(Xorwow128Core, 5);
+make_xorwow_core!(Xorwow160Core, 6);
+make_xorwow_core!(XorwowXor96Core, 4);
+make_xorwow_core!(XorwowXor128Core, 5);
+make_xorwow_core!(XorwowXor160Core, 6);
+
 macro_rules! make_xorwow {
     ($(#[$meta:meta])*
-     $name: ident, $nr: expr) => (
+     $name: ident, $core: ident) => (
         $(#[$meta])*
-        #[derive(Debug, Default, Clone, Eq, PartialEq)]
-        #[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+        #[derive(Debug, Clone)]
         pub struct $name {
-            s: [u32; $nr]
+            inner: BlockRng64<$core>,
         }
     )
 }
@@ -57,12 +120,35 @@ make_xorwow!(
 /// use xorwowgen::Xorwow96;
 ///
 /// let mut rng = Xorwow96::seed_from_u64(4321);
-/// 
+///
 /// for _ in 0..100 { rng.next_u32(); }
 ///
-/// assert_eq!(4911005502369895850, rng.next_u64());
+/// assert_eq!(10000373240407099163, rng.next_u64());
+/// ```
+///
+/// # Splitting into substreams
 /// ```
-    Xorwow96, 4);
+/// use rand_core::{SeedableRng, RngCore};
+/// use xorwowgen::Xorwow96;
+///
+/// let mut rng = Xorwow96::seed_from_u64(4321);
+/// rng.jump();
+///
+/// assert_eq!(2210429460, rng.next_u32());
+/// ```
+///
+/// # Independent substreams via a custom increment
+/// ```
+/// use rand_core::{SeedableRng, RngCore};
+/// use xorwowgen::Xorwow96;
+///
+/// // same seed, different (odd) increments
+/// let mut a = Xorwow96::with_increment([0u8; 16], 3);
+/// let mut b = Xorwow96::with_increment([0u8; 16], 5);
+///
+/// assert_ne!(a.next_u32(), b.next_u32());
+/// ```
+    Xorwow96, Xorwow96Core);
 
 make_xorwow!(
 /// Xorwow implementation with __128__ bits of state
@@ -74,12 +160,23 @@ make_xorwow!(
 /// use xorwowgen::Xorwow128;
 ///
 /// let mut rng = Xorwow128::seed_from_u64(4321);
-/// 
+///
 /// for _ in 0..100 { rng.next_u32(); }
 ///
-/// assert_eq!(4097996158316656424, rng.next_u64());
+/// assert_eq!(10806567959012631657, rng.next_u64());
+/// ```
+///
+/// # Splitting into substreams
 /// ```
-    Xorwow128, 5);
+/// use rand_core::{SeedableRng, RngCore};
+/// use xorwowgen::Xorwow128;
+///
+/// let mut rng = Xorwow128::seed_from_u64(4321);
+/// rng.jump();
+///
+/// assert_eq!(4101369527, rng.next_u32());
+/// ```
+    Xorwow128, Xorwow128Core);
 
 make_xorwow!(
 /// Xorwow implementation with __160__ bits of state
@@ -91,12 +188,23 @@ make_xorwow!(
 /// use xorwowgen::Xorwow160;
 ///
 /// let mut rng = Xorwow160::seed_from_u64(4321);
-/// 
+///
 /// for _ in 0..50 { rng.next_u32(); }
 ///
-/// assert_eq!(1148765721, rng.next_u32());
+/// assert_eq!(603042516, rng.next_u32());
 /// ```
-    Xorwow160, 6);
+///
+/// # Splitting into substreams
+/// ```
+/// use rand_core::{SeedableRng, RngCore};
+/// use xorwowgen::Xorwow160;
+///
+/// let mut rng = Xorwow160::seed_from_u64(4321);
+/// rng.long_jump();
+///
+/// assert_eq!(1203311502, rng.next_u32());
+/// ```
+    Xorwow160, Xorwow160Core);
 
 make_xorwow!(
 /// Xorwow implementation with a footprint of __96__ bits
@@ -113,9 +221,9 @@ make_xorwow!(
 ///
 /// for _ in 0..50 { rng.next_u32(); }
 ///
-/// assert_eq!(1471510243, rng.next_u32());
+/// assert_eq!(3512043517, rng.next_u32());
 /// ```
-    XorwowXor96, 4);
+    XorwowXor96, XorwowXor96Core);
 
 make_xorwow!(
 /// Xorwow implementation with a footprint of __128__ bits
@@ -132,9 +240,9 @@ make_xorwow!(
 ///
 /// for _ in 0..50 { rng.next_u32(); }
 ///
-/// assert_eq!(2515325973, rng.next_u32());
+/// assert_eq!(4010099444, rng.next_u32());
 /// ```
-    XorwowXor128, 5);
+    XorwowXor128, XorwowXor128Core);
 
 make_xorwow!(
 /// Xorwow implementation with a footprint of __160__ bits
@@ -151,15 +259,15 @@ make_xorwow!(
 ///
 /// for _ in 0..50 { rng.next_u32(); }
 ///
-/// assert_eq!(1111799269, rng.next_u32());
+/// assert_eq!(602809040, rng.next_u32());
 /// ```
-    XorwowXor160, 6);
+    XorwowXor160, XorwowXor160Core);
 
-macro_rules! impl_xorwow {
-    ($name: ident, $mod: ident, $nr: expr, $shift: expr) => {
-        impl $name {
-           
-            fn clock(&mut self) {
+macro_rules! impl_xorwow_core {
+    ($core: ident, $mod: ident, $nr: expr, $shift: expr, $jump: expr, $long_jump: expr) => {
+        impl $core {
+
+            fn clock_xorshift(&mut self) {
                 let mut x = self.s[$nr - 2];
 
                 let y = self.s[0];
@@ -169,16 +277,63 @@ macro_rules! impl_xorwow {
                 }
 
                 self.s[1] = y;
-                
+
                 x ^= x >> $shift.0;
                 x ^= x << $shift.1;
                 x ^= y ^ (y << $shift.2);
 
                 self.s[0] = x;
+            }
+
+            fn clock(&mut self) {
+                self.clock_xorshift();
+
+                // according to the paper, the increment could be any
+                // odd number; defaults to 362437, see `with_increment`
+                self.s[$nr - 1] = self.s[$nr - 1].wrapping_add(self.increment);
+            }
+
+            // Advances the Xorshift portion of the state by `exponent`
+            // clocks at once, using the precomputed bit-vector `jump`
+            // (coefficients of `x^exponent mod M(x)`) instead of looping
+            // `exponent` times.
+            fn apply_jump(&mut self, jump: &[u32; $nr - 1], exponent: u64) {
+                let mut acc = [0u32; $nr - 1];
+
+                for &word in jump.iter() {
+                    let mut bits = word;
+                    for _ in 0..32 {
+                        if bits & 1 == 1 {
+                            for i in 0..($nr - 1) {
+                                acc[i] ^= self.s[i];
+                            }
+                        }
+                        self.clock_xorshift();
+                        bits >>= 1;
+                    }
+                }
+
+                self.s[..($nr - 1)].copy_from_slice(&acc);
+
+                // the Weyl counter's recurrence is affine, so it can be
+                // updated in one step rather than by accumulating bits
+                self.s[$nr - 1] = self.s[$nr - 1].wrapping_add((exponent as u32).wrapping_mul(self.increment));
+            }
+
+            /// Advances the state as if `clock()` had been called
+            /// [`crate::JUMP_EXPONENT`] times, without materializing the
+            /// intermediate states. Equivalent, cheaper way of splitting a
+            /// single generator into non-overlapping streams.
+            pub fn jump(&mut self) {
+                self.apply_jump(&$jump, crate::JUMP_EXPONENT);
+            }
 
-                // according to the paper, '362437' could be any
-                // odd number
-                self.s[$nr - 1] = self.s[$nr - 1].wrapping_add(362437);
+            /// Like [`Self::jump`], but advances the state
+            /// [`crate::LONG_JUMP_EXPONENT`] steps, for carving out
+            /// substreams far enough apart that a `jump()`-sized substream
+            /// cannot run into the next one.
+            pub fn long_jump(&mut self) {
+                self.apply_jump(&$long_jump, crate::LONG_JUMP_EXPONENT);
             }
 
             pub fn return_u32(&mut self) -> u32 {
@@ -188,7 +343,7 @@ macro_rules! impl_xorwow {
                 // can be done using + or XOR
                 self.s[0].$mod(self.s[$nr - 1])
             }
-            
+
             pub fn return_u64(&mut self) -> u64 {
                 self.clock();
 
@@ -202,19 +357,30 @@ macro_rules! impl_xorwow {
                 self.s
             }
         }
+
+        impl BlockRngCore for $core {
+            type Item = u64;
+            type Results = [u64; BLOCK_WORDS];
+
+            fn generate(&mut self, results: &mut Self::Results) {
+                for r in results.iter_mut() {
+                    *r = self.return_u64();
+                }
+            }
+        }
     };
 }
 
-impl_xorwow!(Xorwow96, wrapping_add, 4, (10, 5, 26));
-impl_xorwow!(Xorwow128, wrapping_add, 5, (5, 14, 1));
-impl_xorwow!(Xorwow160, wrapping_add, 6, (2, 1, 4));
-impl_xorwow!(XorwowXor96, bitxor, 4, (10, 5, 26));
-impl_xorwow!(XorwowXor128, bitxor, 5, (5, 14, 1));
-impl_xorwow!(XorwowXor160, bitxor, 6, (2, 1, 4));
+impl_xorwow_core!(Xorwow96Core, wrapping_add, 4, (10, 5, 26), JUMP_96, LONG_JUMP_96);
+impl_xorwow_core!(Xorwow128Core, wrapping_add, 5, (5, 14, 1), JUMP_128, LONG_JUMP_128);
+impl_xorwow_core!(Xorwow160Core, wrapping_add, 6, (2, 1, 4), JUMP_160, LONG_JUMP_160);
+impl_xorwow_core!(XorwowXor96Core, bitxor, 4, (10, 5, 26), JUMP_96, LONG_JUMP_96);
+impl_xorwow_core!(XorwowXor128Core, bitxor, 5, (5, 14, 1), JUMP_128, LONG_JUMP_128);
+impl_xorwow_core!(XorwowXor160Core, bitxor, 6, (2, 1, 4), JUMP_160, LONG_JUMP_160);
 
-macro_rules! impl_seedable {
-    ($name: ident, $nr: expr) => {
-        impl SeedableRng for $name {
+macro_rules! impl_seedable_core {
+    ($core: ident, $nr: expr) => {
+        impl SeedableRng for $core {
             type Seed = [u8; $nr * 4];
 
             fn from_seed(seed: [u8; $nr * 4]) -> Self {
@@ -239,7 +405,7 @@ macro_rules! impl_seedable {
                     }
                 }
 
-                Self { s: state }
+                Self { s: state, increment: 362437 }
             }
 
             // Map 2^64 possible values to (2^n)-1 possible states.
@@ -259,37 +425,146 @@ macro_rules! impl_seedable {
                     }
                 }
 
-                Self { s: state }
+                Self { s: state, increment: 362437 }
+            }
+        }
+
+        impl $core {
+            /// Seeds the generator like [`SeedableRng::from_seed`], but
+            /// with the Weyl sequence increment set to `inc | 1` instead
+            /// of the default `362437`. Generators sharing a seed but
+            /// using distinct odd increments produce decorrelated
+            /// streams, which is useful for seeding many generators for
+            /// parallel Monte-Carlo work.
+            pub fn with_increment(seed: <Self as SeedableRng>::Seed, inc: u32) -> Self {
+                let mut state = Self::from_seed(seed);
+                state.increment = inc | 1;
+                state
             }
         }
     };
 }
 
-impl_seedable!(Xorwow96, 4);
-impl_seedable!(Xorwow128, 5);
-impl_seedable!(Xorwow160, 6);
-impl_seedable!(XorwowXor96, 4);
-impl_seedable!(XorwowXor128, 5);
-impl_seedable!(XorwowXor160, 6);
+impl_seedable_core!(Xorwow96Core, 4);
+impl_seedable_core!(Xorwow128Core, 5);
+impl_seedable_core!(Xorwow160Core, 6);
+impl_seedable_core!(XorwowXor96Core, 4);
+impl_seedable_core!(XorwowXor128Core, 5);
+impl_seedable_core!(XorwowXor160Core, 6);
+
+macro_rules! impl_xorwow {
+    ($name: ident, $core: ident, $nr: expr) => {
+        impl $name {
+            pub fn return_u32(&mut self) -> u32 {
+                let value = self.inner.core.return_u32();
+                self.inner.reset();
+                value
+            }
+
+            pub fn return_u64(&mut self) -> u64 {
+                let value = self.inner.core.return_u64();
+                self.inner.reset();
+                value
+            }
+
+            pub fn dump_state(&self) -> [u32; $nr] {
+                self.inner.core.dump_state()
+            }
+
+            /// See [`Self::long_jump`] for a jump far enough to carve out
+            /// a non-overlapping substream of substreams.
+            pub fn jump(&mut self) {
+                self.inner.core.jump();
+                self.inner.reset();
+            }
+
+            /// See the inner core's `long_jump` for details.
+            pub fn long_jump(&mut self) {
+                self.inner.core.long_jump();
+                self.inner.reset();
+            }
+
+            /// See the inner core's `with_increment` for details.
+            pub fn with_increment(seed: <$core as SeedableRng>::Seed, inc: u32) -> Self {
+                Self { inner: BlockRng64::new(<$core>::with_increment(seed, inc)) }
+            }
+        }
+
+        impl SeedableRng for $name {
+            type Seed = <$core as SeedableRng>::Seed;
+
+            fn from_seed(seed: Self::Seed) -> Self {
+                Self { inner: BlockRng64::new(<$core>::from_seed(seed)) }
+            }
+
+            fn seed_from_u64(seed: u64) -> Self {
+                Self { inner: BlockRng64::new(<$core>::seed_from_u64(seed)) }
+            }
+        }
+
+        // `BlockRng64` derives neither `Default` nor `PartialEq`/`Eq`, so
+        // these forward to `inner.core`, matching what this type derived
+        // before it was wrapped in a `BlockRng64`.
+        impl Default for $name {
+            fn default() -> Self {
+                Self { inner: BlockRng64::new($core { s: [0; $nr], increment: 362437 }) }
+            }
+        }
+
+        impl PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                self.inner.core == other.inner.core
+            }
+        }
+
+        impl Eq for $name {}
+
+        #[cfg(feature = "serde1")]
+        impl Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.inner.core.serialize(serializer)
+            }
+        }
+
+        #[cfg(feature = "serde1")]
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                Ok(Self { inner: BlockRng64::new(<$core>::deserialize(deserializer)?) })
+            }
+        }
+    };
+}
 
+impl_xorwow!(Xorwow96, Xorwow96Core, 4);
+impl_xorwow!(Xorwow128, Xorwow128Core, 5);
+impl_xorwow!(Xorwow160, Xorwow160Core, 6);
+impl_xorwow!(XorwowXor96, XorwowXor96Core, 4);
+impl_xorwow!(XorwowXor128, XorwowXor128Core, 5);
+impl_xorwow!(XorwowXor160, XorwowXor160Core, 6);
+
+/// Shared by every generator in this crate: delegates `RngCore` to the
+/// `BlockRng64`-wrapped `inner` field. Every generator in this crate now
+/// buffers `u64` items generated by `return_u64()`, so `next_u32`/`next_u64`/
+/// `fill_bytes` all serve out of (and stay in lockstep with) that single
+/// buffered stream, the same way `return_u64()` itself would.
+#[macro_export]
 macro_rules! impl_core {
     ($name: ident) => {
         impl RngCore for $name {
             fn next_u32(&mut self) -> u32 {
-                self.return_u32()
+                self.inner.next_u32()
             }
 
             fn next_u64(&mut self) -> u64 {
-                self.return_u64()
+                self.inner.next_u64()
             }
 
             fn fill_bytes(&mut self, dest: &mut [u8]) {
-                fill_bytes_via_next(self, dest);
+                self.inner.fill_bytes(dest);
             }
 
             fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
-                self.fill_bytes(dest);
-                Ok(())
+                self.inner.try_fill_bytes(dest)
             }
         }
     };
@@ -301,3 +576,74 @@ impl_core!(Xorwow160);
 impl_core!(XorwowXor96);
 impl_core!(XorwowXor128);
 impl_core!(XorwowXor160);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Coefficients of `x^12345 mod M(x)`, for the same shift triples as
+    // `JUMP_96`/`JUMP_128`/`JUMP_160`, computed independently of
+    // `apply_jump` (by Gaussian elimination over the Krylov sequence of
+    // `clock_xorshift`, not by exercising the code under test). 12345 is
+    // small enough to also check directly against 12345 sequential
+    // `clock_xorshift` calls below, unlike the real `JUMP_EXPONENT` (2^32).
+    const TEST_JUMP_96: [u32; 3] = [0xea238fc6, 0x312daca9, 0xb939eda9];
+    const TEST_JUMP_128: [u32; 4] = [0x3887732b, 0xd574bd6b, 0xc1545063, 0xb0e9c4b8];
+    const TEST_JUMP_160: [u32; 5] = [0xd933ffa8, 0xb6264f7d, 0xc37999e1, 0x88cf305d, 0xf44b2b68];
+    const TEST_EXPONENT: u64 = 12345;
+
+    macro_rules! test_jump_matches_sequential_clocks {
+        ($test_name: ident, $core: ident, $nr: expr, $test_jump: expr) => {
+            #[test]
+            fn $test_name() {
+                let mut by_jump = $core::seed_from_u64(1);
+                by_jump.apply_jump(&$test_jump, TEST_EXPONENT);
+
+                let mut by_clock = $core::seed_from_u64(1);
+                for _ in 0..TEST_EXPONENT {
+                    by_clock.clock_xorshift();
+                }
+
+                assert_eq!(by_jump.s[..$nr - 1], by_clock.s[..$nr - 1]);
+            }
+        };
+    }
+
+    test_jump_matches_sequential_clocks!(xorwow96_jump_matches_sequential_clocks, Xorwow96Core, 4, TEST_JUMP_96);
+    test_jump_matches_sequential_clocks!(xorwow128_jump_matches_sequential_clocks, Xorwow128Core, 5, TEST_JUMP_128);
+    test_jump_matches_sequential_clocks!(xorwow160_jump_matches_sequential_clocks, Xorwow160Core, 6, TEST_JUMP_160);
+    test_jump_matches_sequential_clocks!(xorwow_xor96_jump_matches_sequential_clocks, XorwowXor96Core, 4, TEST_JUMP_96);
+    test_jump_matches_sequential_clocks!(xorwow_xor128_jump_matches_sequential_clocks, XorwowXor128Core, 5, TEST_JUMP_128);
+    test_jump_matches_sequential_clocks!(xorwow_xor160_jump_matches_sequential_clocks, XorwowXor160Core, 6, TEST_JUMP_160);
+
+    // `jump()`/`long_jump()` advance state by `JUMP_EXPONENT`/
+    // `LONG_JUMP_EXPONENT` sequential clocks respectively, and
+    // `LONG_JUMP_EXPONENT == JUMP_EXPONENT * (1 << 16)`, so calling
+    // `jump()` `1 << 16` times must land on the exact state `long_jump()`
+    // reaches in one call. This catches a wrong `JUMP_*`/`LONG_JUMP_*`
+    // constant that a fixed-value doctest would not, since a wrong
+    // constant still produces *some* output for the doctest to assert.
+    macro_rules! test_long_jump_eq_repeated_jump {
+        ($test_name: ident, $name: ident) => {
+            #[test]
+            fn $test_name() {
+                let mut by_jump = $name::seed_from_u64(1);
+                for _ in 0..(LONG_JUMP_EXPONENT / JUMP_EXPONENT) {
+                    by_jump.jump();
+                }
+
+                let mut by_long_jump = $name::seed_from_u64(1);
+                by_long_jump.long_jump();
+
+                assert_eq!(by_jump.dump_state(), by_long_jump.dump_state());
+            }
+        };
+    }
+
+    test_long_jump_eq_repeated_jump!(xorwow96_long_jump_eq_repeated_jump, Xorwow96);
+    test_long_jump_eq_repeated_jump!(xorwow128_long_jump_eq_repeated_jump, Xorwow128);
+    test_long_jump_eq_repeated_jump!(xorwow160_long_jump_eq_repeated_jump, Xorwow160);
+    test_long_jump_eq_repeated_jump!(xorwow_xor96_long_jump_eq_repeated_jump, XorwowXor96);
+    test_long_jump_eq_repeated_jump!(xorwow_xor128_long_jump_eq_repeated_jump, XorwowXor128);
+    test_long_jump_eq_repeated_jump!(xorwow_xor160_long_jump_eq_repeated_jump, XorwowXor160);
+}
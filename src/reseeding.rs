@@ -0,0 +1,125 @@
+//! Wraps a generator with periodic reseeding from an external entropy
+//! source, for long-running use of these fast, non-cryptographic
+//! generators.
+
+use rand_core::{Error, RngCore, SeedableRng};
+
+/// Wraps an `R: RngCore + SeedableRng` generator and, once `threshold`
+/// bytes have been produced, reseeds it from a supplied `Rsdr: RngCore`
+/// (e.g. `rand_core::OsRng`) via `R::from_seed`.
+///
+/// # Example
+/// ```
+/// use rand_core::{SeedableRng, RngCore};
+/// use xorwowgen::Xorwow96;
+/// use xorwowgen::reseeding::Reseeding;
+///
+/// let inner = Xorwow96::seed_from_u64(1);
+/// let reseeder = Xorwow96::seed_from_u64(2);
+/// let mut rng = Reseeding::new(inner, reseeder, 8);
+///
+/// for _ in 0..3 {
+///     rng.next_u32();
+/// }
+///
+/// assert_eq!(269885208, rng.next_u32());
+/// ```
+pub struct Reseeding<R: RngCore + SeedableRng, Rsdr: RngCore> {
+    inner: R,
+    reseeder: Rsdr,
+    threshold: u64,
+    produced: u64,
+}
+
+impl<R: RngCore + SeedableRng, Rsdr: RngCore> Reseeding<R, Rsdr> {
+    /// Wraps `inner`, reseeding it via `reseeder` once `threshold` bytes
+    /// have been produced.
+    pub fn new(inner: R, reseeder: Rsdr, threshold: u64) -> Self {
+        Self { inner, reseeder, threshold, produced: 0 }
+    }
+
+    /// Number of bytes produced by the wrapped generator since the last
+    /// reseed (or since construction).
+    pub fn produced(&self) -> u64 {
+        self.produced
+    }
+
+    // pulls a fresh seed from `reseeder` and re-initializes `inner` via
+    // `R::from_seed`, which already guards against the all-zero seed
+    fn reseed(&mut self) {
+        let mut seed = R::Seed::default();
+        self.reseeder.fill_bytes(seed.as_mut());
+        self.inner = R::from_seed(seed);
+        self.produced = 0;
+    }
+
+    fn reseed_if_due(&mut self) {
+        if self.produced >= self.threshold {
+            self.reseed();
+        }
+    }
+}
+
+impl<R: RngCore + SeedableRng, Rsdr: RngCore> RngCore for Reseeding<R, Rsdr> {
+    fn next_u32(&mut self) -> u32 {
+        self.reseed_if_due();
+        self.produced += 4;
+        self.inner.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.reseed_if_due();
+        self.produced += 8;
+        self.inner.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.reseed_if_due();
+        self.produced += dest.len() as u64;
+        self.inner.fill_bytes(dest);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.reseed_if_due();
+        self.produced += dest.len() as u64;
+        self.inner.try_fill_bytes(dest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Xorwow96;
+
+    #[test]
+    fn produced_resets_once_threshold_is_crossed() {
+        let mut rng = Reseeding::new(Xorwow96::seed_from_u64(1), Xorwow96::seed_from_u64(2), 8);
+
+        rng.next_u32();
+        assert_eq!(rng.produced(), 4);
+        rng.next_u32();
+        assert_eq!(rng.produced(), 8);
+
+        // `produced == threshold` is already due; this call reseeds
+        // before counting its own 4 bytes
+        rng.next_u32();
+        assert_eq!(rng.produced(), 4);
+    }
+
+    #[test]
+    fn output_switches_to_the_reseeded_generator() {
+        let mut rng = Reseeding::new(Xorwow96::seed_from_u64(1), Xorwow96::seed_from_u64(2), 8);
+
+        rng.next_u32();
+        rng.next_u32();
+
+        // the reseeder is untouched up to here, so pulling its first seed
+        // now reproduces exactly what the third `next_u32()` call reseeds
+        // `inner` to
+        let mut seed = <Xorwow96 as SeedableRng>::Seed::default();
+        Xorwow96::seed_from_u64(2).fill_bytes(seed.as_mut());
+        let mut expected = Xorwow96::from_seed(seed);
+
+        assert_eq!(rng.next_u32(), expected.next_u32());
+    }
+}
@@ -1,12 +1,12 @@
 //! Xorwow derivatives with 2 * 64 = 128 bits of state and
 //! a modulo 2^64 counter.
-//! 
+//!
 //! Source of the shift triple of the underlying Xorshift
 //! generator:
 //! [https://vigna.di.unimi.it/ftp/papers/xorshiftplus.pdf](https://vigna.di.unimi.it/ftp/papers/xorshiftplus.pdf)
 
+use rand_core::block::{BlockRng64, BlockRngCore};
 use rand_core::{SeedableRng, RngCore, Error};
-use rand_core::impls::fill_bytes_via_next;
 use rand_core::le::read_u64_into;
 use std::ops::BitXor;
 use crate::impl_core;
@@ -14,14 +14,39 @@ use crate::impl_core;
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
 
+/// Number of `u64` words clocked out per call to [`BlockRngCore::generate`].
+const BLOCK_WORDS: usize = 8;
+
+/// Coefficients (low word first) of `x^JUMP_EXPONENT mod M(x)`, where
+/// `M(x)` is the characteristic polynomial over GF(2) of the Xorshift
+/// update with shift triple `(23, 17, 26)` and 2 state words. Shared by
+/// both combining methods below, since they use the same Xorshift update.
+const JUMP: [u64; 2] = [0x246bea459fdc8d3f, 0x6b385b431ee8b1de];
+/// Coefficients of `x^LONG_JUMP_EXPONENT mod M(x)`, same map as [`JUMP`].
+const LONG_JUMP: [u64; 2] = [0x12994b69bc4fa3a9, 0x201373caab520c79];
+
+macro_rules! make_xorwow128_core {
+    ($core: ident) => {
+        #[derive(Debug, Clone, Eq, PartialEq)]
+        #[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+        pub struct $core {
+            s: [u64; 3],
+            // Weyl sequence increment; must stay odd, see `with_increment`.
+            increment: u64,
+        }
+    };
+}
+
+make_xorwow128_core!(LargeWrapCore);
+make_xorwow128_core!(LargeXorCore);
+
 macro_rules! make_xorwow128 {
     ($(#[$meta:meta])*
-    $name: ident) => (
+    $name: ident, $core: ident) => (
         $(#[$meta])*
-        #[derive(Debug, Clone, Eq, PartialEq)]
-        #[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+        #[derive(Debug, Clone)]
         pub struct $name {
-            s: [u64; 3]
+            inner: BlockRng64<$core>,
         }
     )
 }
@@ -39,7 +64,18 @@ make_xorwow128!(
 ///
 /// assert_eq!(6194833746010933040, rng.next_u64());
 /// ```
-    LargeWrap);
+///
+/// # Splitting into substreams
+/// ```
+/// use xorwowgen::xorwow128::LargeWrap;
+/// use rand_core::{SeedableRng, RngCore};
+///
+/// let mut rng = LargeWrap::seed_from_u64(9876543214321);
+/// rng.jump();
+///
+/// assert_eq!(818193337362735867, rng.next_u64());
+/// ```
+    LargeWrap, LargeWrapCore);
 
 make_xorwow128!(
 /// Modification of the underlying Xorshift stream is
@@ -54,13 +90,13 @@ make_xorwow128!(
 ///
 /// assert_eq!(2242453002770973956, rng.next_u64());
 /// ```
-    LargeXor);
+    LargeXor, LargeXorCore);
 
 // a = 23, b = 17, c = 26
-macro_rules! impl_xorwow128 {
-    ($name: ident, $mod: ident, $shift: expr) => {
-        impl $name {
-            fn clock(&mut self) {
+macro_rules! impl_xorwow128_core {
+    ($core: ident, $mod: ident, $shift: expr) => {
+        impl $core {
+            fn clock_xorshift(&mut self) {
                 let mut a = self.s[0];
                 let b = self.s[1];
                 self.s[0] = b;
@@ -68,7 +104,50 @@ macro_rules! impl_xorwow128 {
                 a ^= a >> $shift.1;
                 a ^= b ^ (b >> $shift.2);
                 self.s[1] = a;
-                self.s[2] = self.s[2].wrapping_add(0x587CC7F5F9DD5);
+            }
+
+            fn clock(&mut self) {
+                self.clock_xorshift();
+                self.s[2] = self.s[2].wrapping_add(self.increment);
+            }
+
+            fn apply_jump(&mut self, jump: &[u64; 2], exponent: u64) {
+                let mut acc = [0u64; 2];
+
+                for &word in jump.iter() {
+                    let mut bits = word;
+                    for _ in 0..64 {
+                        if bits & 1 == 1 {
+                            acc[0] ^= self.s[0];
+                            acc[1] ^= self.s[1];
+                        }
+                        self.clock_xorshift();
+                        bits >>= 1;
+                    }
+                }
+
+                self.s[0] = acc[0];
+                self.s[1] = acc[1];
+
+                // the Weyl counter's recurrence is affine, so it can be
+                // updated in one step rather than by accumulating bits
+                self.s[2] = self.s[2].wrapping_add(exponent.wrapping_mul(self.increment));
+            }
+
+            /// Advances the state as if `clock()` had been called
+            /// [`crate::JUMP_EXPONENT`] times, without materializing the
+            /// intermediate states. Equivalent, cheaper way of splitting a
+            /// single generator into non-overlapping streams.
+            pub fn jump(&mut self) {
+                self.apply_jump(&JUMP, crate::JUMP_EXPONENT);
+            }
+
+            /// Like [`Self::jump`], but advances the state
+            /// [`crate::LONG_JUMP_EXPONENT`] steps, for carving out
+            /// substreams far enough apart that a `jump()`-sized substream
+            /// cannot run into the next one.
+            pub fn long_jump(&mut self) {
+                self.apply_jump(&LONG_JUMP, crate::LONG_JUMP_EXPONENT);
             }
 
             pub fn return_u32(&mut self) -> u32 {
@@ -85,15 +164,26 @@ macro_rules! impl_xorwow128 {
                 self.s
             }
         }
+
+        impl BlockRngCore for $core {
+            type Item = u64;
+            type Results = [u64; BLOCK_WORDS];
+
+            fn generate(&mut self, results: &mut Self::Results) {
+                for r in results.iter_mut() {
+                    *r = self.return_u64();
+                }
+            }
+        }
     }
 }
 
-impl_xorwow128!(LargeWrap, wrapping_add, (23, 17, 26));
-impl_xorwow128!(LargeXor, bitxor, (23, 17, 26));
+impl_xorwow128_core!(LargeWrapCore, wrapping_add, (23, 17, 26));
+impl_xorwow128_core!(LargeXorCore, bitxor, (23, 17, 26));
 
-macro_rules! impl_seedable {
-    ($name: ident) => {
-        impl SeedableRng for $name {
+macro_rules! impl_seedable128_core {
+    ($core: ident) => {
+        impl SeedableRng for $core {
             type Seed = [u8; 24];
 
             fn from_seed(seed: [u8; 24]) -> Self {
@@ -105,13 +195,13 @@ macro_rules! impl_seedable {
                     state[0] = u64::MAX;
                     state[1] = u64::MAX;
                 }
-                
-                Self { s: state }
+
+                Self { s: state, increment: 0x587CC7F5F9DD5 }
             }
 
             fn seed_from_u64(seed: u64) -> Self {
                 let mut state = [0u64; 3];
-                
+
                 if seed == 0u64 {
                     state[0] = u64::MAX;
                 } else {
@@ -121,14 +211,168 @@ macro_rules! impl_seedable {
                 state[1] = seed;
                 state[2] = !seed;
 
-                Self { s: state }
+                Self { s: state, increment: 0x587CC7F5F9DD5 }
+            }
+        }
+
+        impl $core {
+            /// Seeds the generator like [`SeedableRng::from_seed`], but
+            /// with the Weyl sequence increment set to `inc | 1` instead
+            /// of the default `0x587CC7F5F9DD5`. Generators sharing a seed
+            /// but using distinct odd increments produce decorrelated
+            /// streams, which is useful for seeding many generators for
+            /// parallel Monte-Carlo work.
+            pub fn with_increment(seed: <Self as SeedableRng>::Seed, inc: u64) -> Self {
+                let mut state = Self::from_seed(seed);
+                state.increment = inc | 1;
+                state
+            }
+        }
+    };
+}
+
+impl_seedable128_core!(LargeWrapCore);
+impl_seedable128_core!(LargeXorCore);
+
+macro_rules! impl_xorwow128 {
+    ($name: ident, $core: ident) => {
+        impl $name {
+            pub fn return_u32(&mut self) -> u32 {
+                let value = self.inner.core.return_u32();
+                self.inner.reset();
+                value
+            }
+
+            pub fn return_u64(&mut self) -> u64 {
+                let value = self.inner.core.return_u64();
+                self.inner.reset();
+                value
+            }
+
+            pub fn dump_state(&self) -> [u64; 3] {
+                self.inner.core.dump_state()
+            }
+
+            /// See the inner core's `jump` for details.
+            pub fn jump(&mut self) {
+                self.inner.core.jump();
+                self.inner.reset();
+            }
+
+            /// See the inner core's `long_jump` for details.
+            pub fn long_jump(&mut self) {
+                self.inner.core.long_jump();
+                self.inner.reset();
+            }
+
+            /// See the inner core's `with_increment` for details.
+            pub fn with_increment(seed: <$core as SeedableRng>::Seed, inc: u64) -> Self {
+                Self { inner: BlockRng64::new(<$core>::with_increment(seed, inc)) }
+            }
+        }
+
+        impl SeedableRng for $name {
+            type Seed = <$core as SeedableRng>::Seed;
+
+            fn from_seed(seed: Self::Seed) -> Self {
+                Self { inner: BlockRng64::new(<$core>::from_seed(seed)) }
+            }
+
+            fn seed_from_u64(seed: u64) -> Self {
+                Self { inner: BlockRng64::new(<$core>::seed_from_u64(seed)) }
+            }
+        }
+
+        // `BlockRng64` derives neither `PartialEq` nor `Eq`, so these
+        // forward to `inner.core`, matching what this type derived
+        // before it was wrapped in a `BlockRng64`.
+        impl PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                self.inner.core == other.inner.core
+            }
+        }
+
+        impl Eq for $name {}
+
+        #[cfg(feature = "serde1")]
+        impl Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.inner.core.serialize(serializer)
+            }
+        }
+
+        #[cfg(feature = "serde1")]
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                Ok(Self { inner: BlockRng64::new(<$core>::deserialize(deserializer)?) })
             }
         }
     };
 }
 
-impl_seedable!(LargeWrap);
-impl_seedable!(LargeXor);
+impl_xorwow128!(LargeWrap, LargeWrapCore);
+impl_xorwow128!(LargeXor, LargeXorCore);
 
 impl_core!(LargeWrap);
 impl_core!(LargeXor);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Coefficients of `x^12345 mod M(x)`, for the same shift triple as
+    // `JUMP`, computed independently of `apply_jump` (by Gaussian
+    // elimination over the Krylov sequence of `clock_xorshift`, not by
+    // exercising the code under test). 12345 is small enough to also
+    // check directly against 12345 sequential `clock_xorshift` calls
+    // below, unlike the real `JUMP_EXPONENT` (2^32).
+    const TEST_JUMP: [u64; 2] = [0x9096fd31fdbd162c, 0xd900ec31c8838550];
+    const TEST_EXPONENT: u64 = 12345;
+
+    macro_rules! test_jump_matches_sequential_clocks {
+        ($test_name: ident, $core: ident) => {
+            #[test]
+            fn $test_name() {
+                let mut by_jump = $core::seed_from_u64(1);
+                by_jump.apply_jump(&TEST_JUMP, TEST_EXPONENT);
+
+                let mut by_clock = $core::seed_from_u64(1);
+                for _ in 0..TEST_EXPONENT {
+                    by_clock.clock_xorshift();
+                }
+
+                assert_eq!(by_jump.s[..2], by_clock.s[..2]);
+            }
+        };
+    }
+
+    test_jump_matches_sequential_clocks!(large_wrap_jump_matches_sequential_clocks, LargeWrapCore);
+    test_jump_matches_sequential_clocks!(large_xor_jump_matches_sequential_clocks, LargeXorCore);
+
+    // `jump()`/`long_jump()` advance state by `JUMP_EXPONENT`/
+    // `LONG_JUMP_EXPONENT` sequential clocks respectively, and
+    // `LONG_JUMP_EXPONENT == JUMP_EXPONENT * (1 << 16)`, so calling
+    // `jump()` `1 << 16` times must land on the exact state `long_jump()`
+    // reaches in one call. This catches a wrong `JUMP`/`LONG_JUMP`
+    // constant that a fixed-value doctest would not, since a wrong
+    // constant still produces *some* output for the doctest to assert.
+    macro_rules! test_long_jump_eq_repeated_jump {
+        ($test_name: ident, $name: ident) => {
+            #[test]
+            fn $test_name() {
+                let mut by_jump = $name::seed_from_u64(1);
+                for _ in 0..(crate::LONG_JUMP_EXPONENT / crate::JUMP_EXPONENT) {
+                    by_jump.jump();
+                }
+
+                let mut by_long_jump = $name::seed_from_u64(1);
+                by_long_jump.long_jump();
+
+                assert_eq!(by_jump.dump_state(), by_long_jump.dump_state());
+            }
+        };
+    }
+
+    test_long_jump_eq_repeated_jump!(large_wrap_long_jump_eq_repeated_jump, LargeWrap);
+    test_long_jump_eq_repeated_jump!(large_xor_long_jump_eq_repeated_jump, LargeXor);
+}
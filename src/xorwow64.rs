@@ -1,23 +1,55 @@
 //! Very fast Xorwow derivatives. Consist of a single 64
 //! bit state and a modulo 2^64 counter.
 
-use rand_core::{SeedableRng, RngCore, Error};
-use rand_core::impls::fill_bytes_via_next;
+use rand_core::block::{BlockRng64, BlockRngCore};
 use rand_core::le::read_u64_into;
+use rand_core::{SeedableRng, RngCore, Error};
 use std::ops::BitXor;
 use crate::impl_core;
 
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
 
+/// Number of `u64` words clocked out per call to [`BlockRngCore::generate`].
+const BLOCK_WORDS: usize = 8;
+
+/// Coefficients of `x^JUMP_EXPONENT mod M(x)`, where `M(x)` is the
+/// characteristic polynomial over GF(2) of the Xorshift update with shift
+/// triple `(13, 7, 17)`.
+const JUMP_A: [u64; 1] = [0xab6aa55cea21d9c8];
+/// Coefficients of `x^LONG_JUMP_EXPONENT mod M(x)`, same map as [`JUMP_A`].
+const LONG_JUMP_A: [u64; 1] = [0x197b13119030a84d];
+
+/// Coefficients of `x^JUMP_EXPONENT mod M(x)` for the shift triple
+/// `(13, 19, 28)`.
+const JUMP_B: [u64; 1] = [0x6f61be3f17a59bec];
+/// Coefficients of `x^LONG_JUMP_EXPONENT mod M(x)`, same map as [`JUMP_B`].
+const LONG_JUMP_B: [u64; 1] = [0xa3bfd2f00951a8b1];
+
+macro_rules! make_xorwow64_core {
+    ($core: ident) => {
+        #[derive(Debug, Clone, Eq, PartialEq)]
+        #[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+        pub struct $core {
+            s: [u64; 2],
+            // Weyl sequence increment; must stay odd, see `with_increment`.
+            increment: u64,
+        }
+    };
+}
+
+make_xorwow64_core!(WrapACore);
+make_xorwow64_core!(WrapBCore);
+make_xorwow64_core!(XorACore);
+make_xorwow64_core!(XorBCore);
+
 macro_rules! make_xorwow64 {
     ($(#[$meta:meta])*
-    $name: ident) => (
+    $name: ident, $core: ident) => (
         $(#[$meta])*
-        #[derive(Debug, Clone, Eq, PartialEq)]
-        #[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+        #[derive(Debug, Clone)]
         pub struct $name {
-            s: [u64; 2]
+            inner: BlockRng64<$core>,
         }
     )
 }
@@ -39,7 +71,28 @@ make_xorwow64!(
 ///
 /// assert_eq!(1090866054122946625, rng.next_u64());
 /// ```
-    WrapA);
+///
+/// # Splitting into substreams
+/// ```rust
+/// use rand_core::{SeedableRng, RngCore};
+/// use xorwowgen::xorwow64::WrapA;
+///
+/// let mut rng = WrapA::seed_from_u64(987654321);
+/// rng.jump();
+///
+/// assert_eq!(5064880163175829362, rng.next_u64());
+/// ```
+///
+/// # Independent substreams via a custom increment
+/// ```rust
+/// use rand_core::{SeedableRng, RngCore};
+/// use xorwowgen::xorwow64::WrapA;
+///
+/// let mut rng = WrapA::with_increment([0u8; 16], 7);
+///
+/// assert_eq!(1065361351, rng.next_u64());
+/// ```
+    WrapA, WrapACore);
 
 make_xorwow64!(
 /// Utilizes the following triple for the bit shift:
@@ -58,7 +111,7 @@ make_xorwow64!(
 ///
 /// assert_eq!(17419553017648195578, rng.next_u64());
 /// ```
-    WrapB);
+    WrapB, WrapBCore);
 
 make_xorwow64!(
 /// Utilizes the following triple for the bit shift:
@@ -77,7 +130,7 @@ make_xorwow64!(
 ///
 /// assert_eq!(1086342340810259457, rng.next_u64());
 /// ```
-    XorA);
+    XorA, XorACore);
 
 make_xorwow64!(
 /// Utilizes the following triple for the bit shift:
@@ -96,16 +149,57 @@ make_xorwow64!(
 ///
 /// assert_eq!(17419550427514181626, rng.next_u64());
 /// ```
-    XorB);
+    XorB, XorBCore);
 
-macro_rules! impl_xorwow64 {
-    ($name: ident, $mod: ident, $shift: expr) => {
-        impl $name {
-            fn clock(&mut self) {
+macro_rules! impl_xorwow64_core {
+    ($core: ident, $mod: ident, $shift: expr, $jump: expr, $long_jump: expr) => {
+        impl $core {
+            fn clock_xorshift(&mut self) {
                 self.s[0] ^= self.s[0] << $shift.0;
                 self.s[0] ^= self.s[0] >> $shift.1;
                 self.s[0] ^= self.s[0] << $shift.2;
-                self.s[1] = self.s[1].wrapping_add(0x587CC7F5F9DD5);
+            }
+
+            fn clock(&mut self) {
+                self.clock_xorshift();
+                self.s[1] = self.s[1].wrapping_add(self.increment);
+            }
+
+            fn apply_jump(&mut self, jump: &[u64; 1], exponent: u64) {
+                let mut acc = 0u64;
+
+                for &word in jump.iter() {
+                    let mut bits = word;
+                    for _ in 0..64 {
+                        if bits & 1 == 1 {
+                            acc ^= self.s[0];
+                        }
+                        self.clock_xorshift();
+                        bits >>= 1;
+                    }
+                }
+
+                self.s[0] = acc;
+
+                // the Weyl counter's recurrence is affine, so it can be
+                // updated in one step rather than by accumulating bits
+                self.s[1] = self.s[1].wrapping_add(exponent.wrapping_mul(self.increment));
+            }
+
+            /// Advances the state as if `clock()` had been called
+            /// [`crate::JUMP_EXPONENT`] times, without materializing the
+            /// intermediate states. Equivalent, cheaper way of splitting a
+            /// single generator into non-overlapping streams.
+            pub fn jump(&mut self) {
+                self.apply_jump(&$jump, crate::JUMP_EXPONENT);
+            }
+
+            /// Like [`Self::jump`], but advances the state
+            /// [`crate::LONG_JUMP_EXPONENT`] steps, for carving out
+            /// substreams far enough apart that a `jump()`-sized substream
+            /// cannot run into the next one.
+            pub fn long_jump(&mut self) {
+                self.apply_jump(&$long_jump, crate::LONG_JUMP_EXPONENT);
             }
 
             pub fn return_u32(&mut self) -> u32 {
@@ -122,17 +216,28 @@ macro_rules! impl_xorwow64 {
                 self.s
             }
         }
+
+        impl BlockRngCore for $core {
+            type Item = u64;
+            type Results = [u64; BLOCK_WORDS];
+
+            fn generate(&mut self, results: &mut Self::Results) {
+                for r in results.iter_mut() {
+                    *r = self.return_u64();
+                }
+            }
+        }
     }
 }
 
-impl_xorwow64!(WrapA, wrapping_add, (13, 7, 17));
-impl_xorwow64!(WrapB, wrapping_add, (13, 19, 28));
-impl_xorwow64!(XorA, bitxor, (13, 7, 17));
-impl_xorwow64!(XorB, bitxor, (13, 19, 28));
+impl_xorwow64_core!(WrapACore, wrapping_add, (13, 7, 17), JUMP_A, LONG_JUMP_A);
+impl_xorwow64_core!(WrapBCore, wrapping_add, (13, 19, 28), JUMP_B, LONG_JUMP_B);
+impl_xorwow64_core!(XorACore, bitxor, (13, 7, 17), JUMP_A, LONG_JUMP_A);
+impl_xorwow64_core!(XorBCore, bitxor, (13, 19, 28), JUMP_B, LONG_JUMP_B);
 
-macro_rules! impl_seedable {
-    ($name: ident) => {
-        impl SeedableRng for $name {
+macro_rules! impl_seedable64_core {
+    ($core: ident) => {
+        impl SeedableRng for $core {
             type Seed = [u8; 16];
 
             fn from_seed(seed: [u8; 16]) -> Self {
@@ -143,13 +248,13 @@ macro_rules! impl_seedable {
                 if state[0] == 0u64 {
                     state[0] = u64::MAX;
                 }
-                
-                Self { s: state }
+
+                Self { s: state, increment: 0x587CC7F5F9DD5 }
             }
 
             fn seed_from_u64(seed: u64) -> Self {
                 let mut state = [0u64; 2];
-                
+
                 if seed == 0u64 {
                     state[0] = u64::MAX;
                 } else {
@@ -158,18 +263,179 @@ macro_rules! impl_seedable {
 
                 state[1] = seed;
 
-                Self { s: state }
+                Self { s: state, increment: 0x587CC7F5F9DD5 }
+            }
+        }
+
+        impl $core {
+            /// Seeds the generator like [`SeedableRng::from_seed`], but
+            /// with the Weyl sequence increment set to `inc | 1` instead
+            /// of the default `0x587CC7F5F9DD5`. Generators sharing a seed
+            /// but using distinct odd increments produce decorrelated
+            /// streams, which is useful for seeding many generators for
+            /// parallel Monte-Carlo work.
+            pub fn with_increment(seed: <Self as SeedableRng>::Seed, inc: u64) -> Self {
+                let mut state = Self::from_seed(seed);
+                state.increment = inc | 1;
+                state
             }
         }
     };
 }
 
-impl_seedable!(WrapA);
-impl_seedable!(WrapB);
-impl_seedable!(XorA);
-impl_seedable!(XorB);
+impl_seedable64_core!(WrapACore);
+impl_seedable64_core!(WrapBCore);
+impl_seedable64_core!(XorACore);
+impl_seedable64_core!(XorBCore);
+
+macro_rules! impl_xorwow64 {
+    ($name: ident, $core: ident) => {
+        impl $name {
+            pub fn return_u32(&mut self) -> u32 {
+                let value = self.inner.core.return_u32();
+                self.inner.reset();
+                value
+            }
+
+            pub fn return_u64(&mut self) -> u64 {
+                let value = self.inner.core.return_u64();
+                self.inner.reset();
+                value
+            }
+
+            pub fn dump_state(&self) -> [u64; 2] {
+                self.inner.core.dump_state()
+            }
+
+            /// See the inner core's `jump` for details.
+            pub fn jump(&mut self) {
+                self.inner.core.jump();
+                self.inner.reset();
+            }
+
+            /// See the inner core's `long_jump` for details.
+            pub fn long_jump(&mut self) {
+                self.inner.core.long_jump();
+                self.inner.reset();
+            }
+
+            /// See the inner core's `with_increment` for details.
+            pub fn with_increment(seed: <$core as SeedableRng>::Seed, inc: u64) -> Self {
+                Self { inner: BlockRng64::new(<$core>::with_increment(seed, inc)) }
+            }
+        }
+
+        impl SeedableRng for $name {
+            type Seed = <$core as SeedableRng>::Seed;
+
+            fn from_seed(seed: Self::Seed) -> Self {
+                Self { inner: BlockRng64::new(<$core>::from_seed(seed)) }
+            }
+
+            fn seed_from_u64(seed: u64) -> Self {
+                Self { inner: BlockRng64::new(<$core>::seed_from_u64(seed)) }
+            }
+        }
+
+        // `BlockRng64` derives neither `PartialEq` nor `Eq`, so these
+        // forward to `inner.core`, matching what this type derived
+        // before it was wrapped in a `BlockRng64`.
+        impl PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                self.inner.core == other.inner.core
+            }
+        }
+
+        impl Eq for $name {}
+
+        #[cfg(feature = "serde1")]
+        impl Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.inner.core.serialize(serializer)
+            }
+        }
+
+        #[cfg(feature = "serde1")]
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                Ok(Self { inner: BlockRng64::new(<$core>::deserialize(deserializer)?) })
+            }
+        }
+    };
+}
+
+impl_xorwow64!(WrapA, WrapACore);
+impl_xorwow64!(WrapB, WrapBCore);
+impl_xorwow64!(XorA, XorACore);
+impl_xorwow64!(XorB, XorBCore);
 
 impl_core!(WrapA);
 impl_core!(WrapB);
 impl_core!(XorA);
 impl_core!(XorB);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Coefficients of `x^12345 mod M(x)`, for the same shift triples as
+    // `JUMP_A`/`JUMP_B`, computed independently of `apply_jump` (by
+    // Gaussian elimination over the Krylov sequence of `clock_xorshift`,
+    // not by exercising the code under test). 12345 is small enough to
+    // also check directly against 12345 sequential `clock_xorshift`
+    // calls below, unlike the real `JUMP_EXPONENT` (2^32).
+    const TEST_JUMP_A: [u64; 1] = [0x8189a09f77ab922c];
+    const TEST_JUMP_B: [u64; 1] = [0xf347e404e7873a65];
+    const TEST_EXPONENT: u64 = 12345;
+
+    macro_rules! test_jump_matches_sequential_clocks {
+        ($test_name: ident, $core: ident, $test_jump: expr) => {
+            #[test]
+            fn $test_name() {
+                let mut by_jump = $core::seed_from_u64(1);
+                by_jump.apply_jump(&$test_jump, TEST_EXPONENT);
+
+                let mut by_clock = $core::seed_from_u64(1);
+                for _ in 0..TEST_EXPONENT {
+                    by_clock.clock_xorshift();
+                }
+
+                assert_eq!(by_jump.s[0], by_clock.s[0]);
+            }
+        };
+    }
+
+    test_jump_matches_sequential_clocks!(wrap_a_jump_matches_sequential_clocks, WrapACore, TEST_JUMP_A);
+    test_jump_matches_sequential_clocks!(wrap_b_jump_matches_sequential_clocks, WrapBCore, TEST_JUMP_B);
+    test_jump_matches_sequential_clocks!(xor_a_jump_matches_sequential_clocks, XorACore, TEST_JUMP_A);
+    test_jump_matches_sequential_clocks!(xor_b_jump_matches_sequential_clocks, XorBCore, TEST_JUMP_B);
+
+    // `jump()`/`long_jump()` advance state by `JUMP_EXPONENT`/
+    // `LONG_JUMP_EXPONENT` sequential clocks respectively, and
+    // `LONG_JUMP_EXPONENT == JUMP_EXPONENT * (1 << 16)`, so calling
+    // `jump()` `1 << 16` times must land on the exact state `long_jump()`
+    // reaches in one call. This catches a wrong `JUMP_*`/`LONG_JUMP_*`
+    // constant that a fixed-value doctest would not, since a wrong
+    // constant still produces *some* output for the doctest to assert.
+    macro_rules! test_long_jump_eq_repeated_jump {
+        ($test_name: ident, $name: ident) => {
+            #[test]
+            fn $test_name() {
+                let mut by_jump = $name::seed_from_u64(1);
+                for _ in 0..(crate::LONG_JUMP_EXPONENT / crate::JUMP_EXPONENT) {
+                    by_jump.jump();
+                }
+
+                let mut by_long_jump = $name::seed_from_u64(1);
+                by_long_jump.long_jump();
+
+                assert_eq!(by_jump.dump_state(), by_long_jump.dump_state());
+            }
+        };
+    }
+
+    test_long_jump_eq_repeated_jump!(wrap_a_long_jump_eq_repeated_jump, WrapA);
+    test_long_jump_eq_repeated_jump!(wrap_b_long_jump_eq_repeated_jump, WrapB);
+    test_long_jump_eq_repeated_jump!(xor_a_long_jump_eq_repeated_jump, XorA);
+    test_long_jump_eq_repeated_jump!(xor_b_long_jump_eq_repeated_jump, XorB);
+}